@@ -0,0 +1,326 @@
+//! Structured chain identifiers and a runtime registry of known chains.
+//!
+//! `ChainId` used to be a closed enum listing exactly the chains Frostgate
+//! shipped with, which made it impossible to route between two networks of
+//! the same family (e.g. Ethereum mainnet vs. an EVM L2) since they'd both
+//! collapse to the same variant or to `Unknown`. Instead, a `ChainId` is now
+//! a [`ChainFamily`] plus a canonical network id, and new chains can be
+//! described at runtime via [`ChainRegistry`] without touching this type.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// A family of chains that share consensus, addressing, and signature
+/// conventions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChainFamily {
+    /// EVM-compatible chains (Ethereum mainnet, L2s, other EVM networks).
+    Evm,
+    /// Substrate-based chains (Polkadot, parachains).
+    Substrate,
+    /// Solana.
+    Solana,
+    /// Any chain family not yet modeled explicitly.
+    Other,
+}
+
+/// A structured chain identifier: a [`ChainFamily`] plus a network id that
+/// is canonical within that family (e.g. an EVM chain id).
+///
+/// The former `Ethereum`/`Polkadot`/`Solana`/`Unknown` variants are now the
+/// associated constants `ChainId::ETHEREUM`, `ChainId::POLKADOT`,
+/// `ChainId::SOLANA` and `ChainId::UNKNOWN`; their `u64` wire encoding and
+/// `Display` output are unchanged. Any other `ChainId` round-trips losslessly
+/// through serde. Other EVM and Substrate chains also round-trip losslessly
+/// through [`ChainId::to_u64`] / [`ChainId::try_from_u64`] instead of
+/// flattening to `Unknown`; other Solana and `Other`-family chains, and
+/// network ids too large for the tagged encoding, still encode as the
+/// `Unknown` sentinel there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChainId {
+    pub family: ChainFamily,
+    pub network_id: u128,
+}
+
+/// Tag bits used to distinguish a generic EVM/Substrate `ChainId` from the
+/// legacy sentinel values (`0`, `1`, `2`, `u64::MAX`) in the `u64` wire
+/// encoding. Chosen high enough that no real chain id collides with them.
+const EVM_TAG: u64 = 1 << 62;
+const SUBSTRATE_TAG: u64 = 1 << 61;
+
+impl ChainId {
+    /// Ethereum mainnet.
+    pub const ETHEREUM: ChainId = ChainId {
+        family: ChainFamily::Evm,
+        network_id: 1,
+    };
+    /// Polkadot relay chain.
+    pub const POLKADOT: ChainId = ChainId {
+        family: ChainFamily::Substrate,
+        network_id: 0,
+    };
+    /// Solana mainnet.
+    pub const SOLANA: ChainId = ChainId {
+        family: ChainFamily::Solana,
+        network_id: 0,
+    };
+    /// Unknown or unsupported chain.
+    pub const UNKNOWN: ChainId = ChainId {
+        family: ChainFamily::Other,
+        network_id: u128::MAX,
+    };
+
+    /// Builds a `ChainId` for an arbitrary network within `family`.
+    pub fn new(family: ChainFamily, network_id: u128) -> Self {
+        Self { family, network_id }
+    }
+
+    /// Whether this chain uses EVM-style (secp256k1 + Keccak) addressing,
+    /// relevant for EIP-155 replay protection on signatures.
+    pub fn is_evm(&self) -> bool {
+        self.family == ChainFamily::Evm
+    }
+
+    /// Convert chain ID to u64 for serialization.
+    ///
+    /// Built-in chains keep their legacy encoding (`Ethereum` = 0,
+    /// `Polkadot` = 1, `Solana` = 2, `Unknown` = `u64::MAX`). Other EVM and
+    /// Substrate chains are tagged into the high bits so they round-trip
+    /// losslessly; anything that doesn't fit (a family with no `u64`
+    /// encoding, or a network id too large) falls back to the `Unknown`
+    /// sentinel, matching the old behavior for unrepresentable chains.
+    pub fn to_u64(&self) -> u64 {
+        match *self {
+            Self::ETHEREUM => 0,
+            Self::POLKADOT => 1,
+            Self::SOLANA => 2,
+            Self::UNKNOWN => u64::MAX,
+            ChainId {
+                family: ChainFamily::Evm,
+                network_id,
+            } if network_id < EVM_TAG as u128 => EVM_TAG | network_id as u64,
+            ChainId {
+                family: ChainFamily::Substrate,
+                network_id,
+            } if network_id < SUBSTRATE_TAG as u128 => SUBSTRATE_TAG | network_id as u64,
+            _ => u64::MAX,
+        }
+    }
+
+    /// Attempts to convert a u64 into a ChainId.
+    ///
+    /// Always succeeds: unrecognized values decode to [`ChainId::UNKNOWN`],
+    /// mirroring the historical `TryFrom<u64>` behavior.
+    pub fn try_from_u64(value: u64) -> Self {
+        match value {
+            0 => Self::ETHEREUM,
+            1 => Self::POLKADOT,
+            2 => Self::SOLANA,
+            u64::MAX => Self::UNKNOWN,
+            v if v & EVM_TAG != 0 => Self::new(ChainFamily::Evm, (v & !EVM_TAG) as u128),
+            v if v & SUBSTRATE_TAG != 0 => {
+                Self::new(ChainFamily::Substrate, (v & !SUBSTRATE_TAG) as u128)
+            }
+            _ => Self::UNKNOWN,
+        }
+    }
+}
+
+impl std::fmt::Display for ChainId {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            Self::ETHEREUM => write!(f, "Ethereum"),
+            Self::POLKADOT => write!(f, "Polkadot"),
+            Self::SOLANA => write!(f, "Solana"),
+            Self::UNKNOWN => write!(f, "Unknown"),
+            ChainId { family, network_id } => write!(f, "{family:?}({network_id})"),
+        }
+    }
+}
+
+impl std::convert::TryFrom<u64> for ChainId {
+    type Error = ();
+
+    /// Attempts to convert a u64 into a ChainId.
+    ///
+    /// # Errors
+    /// Returns `Ok(ChainId::UNKNOWN)` for unrecognized chain IDs.
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        Ok(Self::try_from_u64(value))
+    }
+}
+
+/// Wire representation of a `ChainId`: built-in chains serialize as the bare
+/// strings they always have, any other chain serializes as a structured
+/// `Chain` variant.
+#[derive(Serialize, Deserialize)]
+enum ChainIdWire {
+    Ethereum,
+    Polkadot,
+    Solana,
+    Chain { family: ChainFamily, network_id: u128 },
+    #[serde(other)]
+    Unknown,
+}
+
+impl From<ChainId> for ChainIdWire {
+    fn from(id: ChainId) -> Self {
+        match id {
+            ChainId::ETHEREUM => ChainIdWire::Ethereum,
+            ChainId::POLKADOT => ChainIdWire::Polkadot,
+            ChainId::SOLANA => ChainIdWire::Solana,
+            ChainId::UNKNOWN => ChainIdWire::Unknown,
+            ChainId { family, network_id } => ChainIdWire::Chain { family, network_id },
+        }
+    }
+}
+
+impl From<ChainIdWire> for ChainId {
+    fn from(wire: ChainIdWire) -> Self {
+        match wire {
+            ChainIdWire::Ethereum => ChainId::ETHEREUM,
+            ChainIdWire::Polkadot => ChainId::POLKADOT,
+            ChainIdWire::Solana => ChainId::SOLANA,
+            ChainIdWire::Unknown => ChainId::UNKNOWN,
+            ChainIdWire::Chain { family, network_id } => ChainId { family, network_id },
+        }
+    }
+}
+
+impl Serialize for ChainId {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ChainIdWire::from(*self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ChainId {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(ChainIdWire::deserialize(deserializer)?.into())
+    }
+}
+
+/// Finality parameters for a registered chain: how long the relay pipeline
+/// must wait before treating a source-chain event as final.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FinalityParams {
+    /// Confirmations (or finality-gadget votes) required before a block on
+    /// this chain is considered final.
+    pub required_confirmations: u64,
+}
+
+/// Metadata describing a chain registered with a [`ChainRegistry`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainInfo {
+    /// The chain's identifier.
+    pub id: ChainId,
+    /// Human-readable name, for logs and UIs.
+    pub display_name: String,
+    /// Finality parameters used by the relay pipeline.
+    pub finality: FinalityParams,
+}
+
+/// A runtime registry of known chains, so new networks can be added with
+/// their id, family, display name, and finality parameters without editing
+/// [`ChainId`].
+#[derive(Debug, Clone, Default)]
+pub struct ChainRegistry {
+    chains: HashMap<ChainId, ChainInfo>,
+}
+
+impl ChainRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry pre-populated with Frostgate's built-in chains.
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::new();
+        registry.register(ChainInfo {
+            id: ChainId::ETHEREUM,
+            display_name: "Ethereum".to_string(),
+            finality: FinalityParams {
+                required_confirmations: 12,
+            },
+        });
+        registry.register(ChainInfo {
+            id: ChainId::POLKADOT,
+            display_name: "Polkadot".to_string(),
+            finality: FinalityParams {
+                required_confirmations: 1,
+            },
+        });
+        registry.register(ChainInfo {
+            id: ChainId::SOLANA,
+            display_name: "Solana".to_string(),
+            finality: FinalityParams {
+                required_confirmations: 32,
+            },
+        });
+        registry
+    }
+
+    /// Registers (or replaces) a chain, returning its previous info if any.
+    pub fn register(&mut self, info: ChainInfo) -> Option<ChainInfo> {
+        self.chains.insert(info.id, info)
+    }
+
+    /// Looks up a registered chain's metadata.
+    pub fn get(&self, id: &ChainId) -> Option<&ChainInfo> {
+        self.chains.get(id)
+    }
+
+    /// Iterates over all registered chains.
+    pub fn iter(&self) -> impl Iterator<Item = &ChainInfo> {
+        self.chains.values()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_u64_round_trip_is_unchanged() {
+        assert_eq!(ChainId::ETHEREUM.to_u64(), 0);
+        assert_eq!(ChainId::POLKADOT.to_u64(), 1);
+        assert_eq!(ChainId::SOLANA.to_u64(), 2);
+        assert_eq!(ChainId::UNKNOWN.to_u64(), u64::MAX);
+        assert_eq!(ChainId::try_from_u64(0), ChainId::ETHEREUM);
+        assert_eq!(ChainId::try_from_u64(1), ChainId::POLKADOT);
+        assert_eq!(ChainId::try_from_u64(2), ChainId::SOLANA);
+        assert_eq!(ChainId::try_from_u64(u64::MAX), ChainId::UNKNOWN);
+    }
+
+    #[test]
+    fn unknown_evm_chain_round_trips_losslessly() {
+        let polygon = ChainId::new(ChainFamily::Evm, 137);
+        let encoded = polygon.to_u64();
+        assert_ne!(encoded, u64::MAX);
+        assert_eq!(ChainId::try_from_u64(encoded), polygon);
+    }
+
+    #[test]
+    fn serde_preserves_legacy_wire_format_for_builtins() {
+        let json = serde_json::to_string(&ChainId::ETHEREUM).unwrap();
+        assert_eq!(json, "\"Ethereum\"");
+        let de: ChainId = serde_json::from_str(&json).unwrap();
+        assert_eq!(de, ChainId::ETHEREUM);
+    }
+
+    #[test]
+    fn registry_looks_up_registered_chains() {
+        let mut registry = ChainRegistry::with_builtins();
+        let polygon_id = ChainId::new(ChainFamily::Evm, 137);
+        registry.register(ChainInfo {
+            id: polygon_id,
+            display_name: "Polygon".to_string(),
+            finality: FinalityParams {
+                required_confirmations: 128,
+            },
+        });
+        assert_eq!(registry.get(&polygon_id).unwrap().display_name, "Polygon");
+        assert_eq!(registry.get(&ChainId::ETHEREUM).unwrap().display_name, "Ethereum");
+    }
+}
@@ -0,0 +1,252 @@
+//! Source-chain finality proofs.
+//!
+//! `MessageStatus` used to jump straight from `InFlight` to `Confirmed`
+//! with no notion of source-chain finality, the safety gate that prevents a
+//! bridge from relaying a message whose emitting block could still be
+//! reorganized away. [`FinalityProof`] lets the relay pipeline track a
+//! message through source-chain finalization before delivery: a GRANDPA
+//! justification for Substrate-family sources, or a confirmation-depth
+//! header chain for EVM sources.
+
+use std::collections::HashSet;
+
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+
+/// A single validator's signed vote ("precommit") for a target block, as
+/// used by GRANDPA. `validator` is an Ed25519 public key, `signature` its
+/// 64-byte signature over the justification's signing payload.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Precommit {
+    /// The voting authority's Ed25519 public key.
+    pub validator: [u8; 32],
+    /// The authority's Ed25519 signature over the vote.
+    pub signature: Vec<u8>,
+}
+
+/// A GRANDPA justification: signed precommits that [`FinalityProof::attests`]
+/// checks against a known authority set for a supermajority, the same way a
+/// light client verifies finality without replaying the chain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GrandpaJustification {
+    /// Hash of the block this justification finalizes.
+    pub target_hash: [u8; 32],
+    /// Number of the block this justification finalizes.
+    pub target_number: u64,
+    /// Precommits, claimed to be from the authority set, voting for
+    /// `target_hash`. Verified against the authority set passed to
+    /// `attests` rather than trusted as-is.
+    pub precommits: Vec<Precommit>,
+    /// Id of the authority set that produced `precommits`.
+    pub authority_set_id: u64,
+}
+
+impl GrandpaJustification {
+    /// The payload each authority signs: `target_hash || target_number ||
+    /// authority_set_id`, all big-endian.
+    fn signing_payload(&self) -> Vec<u8> {
+        let mut payload = Vec::with_capacity(32 + 8 + 8);
+        payload.extend_from_slice(&self.target_hash);
+        payload.extend_from_slice(&self.target_number.to_be_bytes());
+        payload.extend_from_slice(&self.authority_set_id.to_be_bytes());
+        payload
+    }
+
+    /// Counts precommits that come from a distinct member of
+    /// `authority_set` and carry a valid signature over this
+    /// justification's signing payload. Duplicate votes from the same
+    /// validator, and votes from keys outside `authority_set`, are not
+    /// counted.
+    fn count_valid_votes(&self, authority_set: &[[u8; 32]]) -> usize {
+        let payload = self.signing_payload();
+        let authorities: HashSet<[u8; 32]> = authority_set.iter().copied().collect();
+        let mut counted = HashSet::new();
+
+        self.precommits
+            .iter()
+            .filter(|precommit| authorities.contains(&precommit.validator))
+            .filter(|precommit| {
+                let Ok(verifying_key) = VerifyingKey::from_bytes(&precommit.validator) else {
+                    return false;
+                };
+                let Ok(signature_bytes) = <[u8; 64]>::try_from(precommit.signature.as_slice())
+                else {
+                    return false;
+                };
+                let signature = Signature::from_bytes(&signature_bytes);
+                verifying_key.verify(&payload, &signature).is_ok()
+            })
+            .filter(|precommit| counted.insert(precommit.validator))
+            .count()
+    }
+}
+
+/// A block header, linking a block to its parent. Used to prove that a
+/// source-chain event's block has a given confirmation depth.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockHeader {
+    pub hash: [u8; 32],
+    pub parent_hash: [u8; 32],
+    pub number: u64,
+}
+
+/// Proof that a source-chain block has reached finality.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FinalityProof {
+    /// A GRANDPA justification, for Substrate-family sources.
+    Grandpa(GrandpaJustification),
+    /// A confirmation-depth threshold plus the header chain linking the
+    /// emitting block to its deepest known descendant, for EVM sources.
+    Confirmations {
+        required: u64,
+        /// Headers from the emitting block (first) to its deepest known
+        /// descendant (last), each linked to the previous by `parent_hash`.
+        header_chain: Vec<BlockHeader>,
+    },
+}
+
+impl FinalityProof {
+    /// Whether this proof attests that `block_hash` is final.
+    ///
+    /// For a GRANDPA justification, `authority_set` is the known, trusted
+    /// set of validator public keys for the justification's
+    /// `authority_set_id`: the justification targets `block_hash`, and a
+    /// deduplicated supermajority (more than 2/3) of `authority_set`
+    /// members have a verified signature over the justification's signing
+    /// payload. Precommits from keys outside `authority_set`, duplicate
+    /// votes, and invalid signatures don't count toward the threshold.
+    ///
+    /// For a confirmation-depth proof, `authority_set` is unused:
+    /// `block_hash` must head a valid, sufficiently deep header chain.
+    pub fn attests(&self, block_hash: &[u8; 32], authority_set: &[[u8; 32]]) -> bool {
+        match self {
+            FinalityProof::Grandpa(justification) => {
+                justification.target_hash == *block_hash
+                    && !authority_set.is_empty()
+                    && justification.count_valid_votes(authority_set) * 3 > authority_set.len() * 2
+            }
+            FinalityProof::Confirmations {
+                required,
+                header_chain,
+            } => {
+                header_chain.first().map(|header| header.hash) == Some(*block_hash)
+                    && header_chain
+                        .windows(2)
+                        .all(|pair| pair[1].parent_hash == pair[0].hash)
+                    && header_chain.len().saturating_sub(1) as u64 >= *required
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn signed_precommit(
+        signing_key: &SigningKey,
+        justification: &GrandpaJustification,
+    ) -> Precommit {
+        let signature = signing_key.sign(&justification.signing_payload());
+        Precommit {
+            validator: signing_key.verifying_key().to_bytes(),
+            signature: signature.to_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn grandpa_proof_requires_supermajority() {
+        let keys: Vec<SigningKey> = (0..4).map(|i| SigningKey::from_bytes(&[i; 32])).collect();
+        let authority_set: Vec<[u8; 32]> =
+            keys.iter().map(|k| k.verifying_key().to_bytes()).collect();
+        let target_hash = [1u8; 32];
+
+        let mut justification = GrandpaJustification {
+            target_hash,
+            target_number: 100,
+            precommits: vec![],
+            authority_set_id: 0,
+        };
+        justification.precommits = keys[..2]
+            .iter()
+            .map(|key| signed_precommit(key, &justification))
+            .collect();
+
+        let proof = FinalityProof::Grandpa(justification);
+        assert!(
+            !proof.attests(&target_hash, &authority_set),
+            "2/4 is not a 2/3 supermajority"
+        );
+    }
+
+    #[test]
+    fn grandpa_proof_accepts_valid_supermajority() {
+        let keys: Vec<SigningKey> = (0..3).map(|i| SigningKey::from_bytes(&[i; 32])).collect();
+        let authority_set: Vec<[u8; 32]> =
+            keys.iter().map(|k| k.verifying_key().to_bytes()).collect();
+        let target_hash = [7u8; 32];
+
+        let mut justification = GrandpaJustification {
+            target_hash,
+            target_number: 1,
+            precommits: vec![],
+            authority_set_id: 0,
+        };
+        justification.precommits = keys
+            .iter()
+            .map(|key| signed_precommit(key, &justification))
+            .collect();
+
+        let proof = FinalityProof::Grandpa(justification);
+        assert!(proof.attests(&target_hash, &authority_set));
+    }
+
+    #[test]
+    fn grandpa_proof_ignores_duplicate_and_forged_votes() {
+        let keys: Vec<SigningKey> = (0..3).map(|i| SigningKey::from_bytes(&[i; 32])).collect();
+        let authority_set: Vec<[u8; 32]> =
+            keys.iter().map(|k| k.verifying_key().to_bytes()).collect();
+        let target_hash = [9u8; 32];
+
+        let justification = GrandpaJustification {
+            target_hash,
+            target_number: 1,
+            precommits: vec![],
+            authority_set_id: 0,
+        };
+        // A single honest vote, duplicated, plus a forged vote claiming to
+        // be from an authority but with an all-zero (invalid) signature.
+        let honest = signed_precommit(&keys[0], &justification);
+        let forged = Precommit {
+            validator: keys[1].verifying_key().to_bytes(),
+            signature: vec![0u8; 64],
+        };
+        let mut justification = justification;
+        justification.precommits = vec![honest.clone(), honest, forged];
+
+        let proof = FinalityProof::Grandpa(justification);
+        assert!(
+            !proof.attests(&target_hash, &authority_set),
+            "duplicate and forged votes must not count toward the supermajority"
+        );
+    }
+
+    #[test]
+    fn confirmations_proof_checks_depth_and_linkage() {
+        let genesis = BlockHeader { hash: [1; 32], parent_hash: [0; 32], number: 1 };
+        let child = BlockHeader { hash: [2; 32], parent_hash: [1; 32], number: 2 };
+        let grandchild = BlockHeader { hash: [3; 32], parent_hash: [2; 32], number: 3 };
+        let proof = FinalityProof::Confirmations {
+            required: 2,
+            header_chain: vec![genesis.clone(), child, grandchild],
+        };
+        assert!(proof.attests(&genesis.hash, &[]));
+
+        let shallow = FinalityProof::Confirmations {
+            required: 2,
+            header_chain: vec![genesis.clone()],
+        };
+        assert!(!shallow.attests(&genesis.hash, &[]));
+    }
+}
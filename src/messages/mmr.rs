@@ -0,0 +1,243 @@
+//! Merkle Mountain Range (MMR) inclusion proofs for `FrostMessage`.
+//!
+//! An MMR is an append-only accumulator over a growing list of leaves.
+//! Each leaf is `H(payload)`, each internal node is `H(left || right)`, and
+//! leaves are grouped into zero or more perfect binary trees ("peaks") whose
+//! count matches the set bits of the current leaf count. The single MMR
+//! root is formed by "bagging" the peaks together right-to-left, smallest
+//! (most recent) peak outermost: `H(peak_n || H(peak_{n-1} || ... ||
+//! H(peak_1 || peak_0)))`, where `peak_0` is the oldest/largest peak and
+//! `peak_n` is the newest/smallest.
+//!
+//! This lets a light client verify that a message was committed to a source
+//! chain's message log without trusting the relayer that forwarded it.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Hashes a leaf payload (`H(payload)`).
+pub fn hash_leaf(payload: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(payload);
+    hasher.finalize().into()
+}
+
+/// Hashes an internal node (`H(left || right)`).
+pub fn hash_internal(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Bags a list of peaks (ordered from oldest/largest to newest/smallest)
+/// into a single MMR root, right-to-left with the smallest/newest peak
+/// outermost: `H(peak_n || H(peak_{n-1} || ... || H(peak_1 || peak_0)))`.
+fn bag_peaks(peaks: &[[u8; 32]]) -> Option<[u8; 32]> {
+    peaks
+        .iter()
+        .copied()
+        .reduce(|acc, peak| hash_internal(&peak, &acc))
+}
+
+/// Decomposes a leaf count into its peaks, ordered from the oldest/largest
+/// (highest height) to the newest/smallest (lowest height), mirroring the
+/// set bits of `leaf_count` from most significant to least significant.
+fn peak_sizes(leaf_count: u64) -> Vec<(u32, u64)> {
+    (0..64)
+        .rev()
+        .filter(|h| leaf_count & (1u64 << h) != 0)
+        .map(|h| (h as u32, 1u64 << h))
+        .collect()
+}
+
+/// An inclusion proof that a leaf (message) was committed to an MMR at a
+/// given size.
+///
+/// `mmr_size` is the number of leaves the MMR held when this proof was
+/// generated; `leaf_index` is the 0-based position of the leaf among those
+/// leaves.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MmrProof {
+    /// 0-based index of the leaf within the MMR.
+    pub leaf_index: u64,
+    /// `H(payload)` for the leaf being proven.
+    pub leaf_hash: [u8; 32],
+    /// Sibling hashes from the leaf up to its containing peak, in bottom-up
+    /// order. Empty if the leaf is itself a peak.
+    pub merkle_path: Vec<[u8; 32]>,
+    /// All peaks of the MMR at the time of proof generation, ordered from
+    /// oldest/largest to newest/smallest.
+    pub peaks: Vec<[u8; 32]>,
+    /// Total number of leaves committed to the MMR when this proof was
+    /// generated.
+    pub mmr_size: u64,
+}
+
+impl MmrProof {
+    /// Verifies this proof against an expected MMR root.
+    ///
+    /// Walks `merkle_path` up from the leaf to recompute the peak that
+    /// should contain it, checks that peak against the claimed `peaks`
+    /// entry, then bags all `peaks` and compares the result to `expected_root`.
+    pub fn verify(&self, expected_root: &[u8; 32]) -> bool {
+        if self.leaf_index >= self.mmr_size {
+            return false;
+        }
+
+        let sizes = peak_sizes(self.mmr_size);
+        let mut start = 0u64;
+        let mut located = None;
+        for (peak_idx, &(height, size)) in sizes.iter().enumerate() {
+            if self.leaf_index < start + size {
+                located = Some((peak_idx, height, self.leaf_index - start));
+                break;
+            }
+            start += size;
+        }
+        let Some((peak_idx, height, local_index)) = located else {
+            return false;
+        };
+        if self.merkle_path.len() != height as usize || peak_idx >= self.peaks.len() {
+            return false;
+        }
+
+        let mut node = self.leaf_hash;
+        for (level, sibling) in self.merkle_path.iter().enumerate() {
+            node = if (local_index >> level) & 1 == 0 {
+                hash_internal(&node, sibling)
+            } else {
+                hash_internal(sibling, &node)
+            };
+        }
+
+        if node != self.peaks[peak_idx] {
+            return false;
+        }
+
+        bag_peaks(&self.peaks).as_ref() == Some(expected_root)
+    }
+}
+
+/// A minimal in-memory MMR accumulator, used by relayers to commit messages
+/// and generate [`MmrProof`]s for them.
+#[derive(Debug, Default, Clone)]
+pub struct Mmr {
+    leaves: Vec<[u8; 32]>,
+}
+
+impl Mmr {
+    /// Creates an empty MMR.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a leaf hash, returning its 0-based leaf index.
+    pub fn append(&mut self, leaf_hash: [u8; 32]) -> u64 {
+        self.leaves.push(leaf_hash);
+        (self.leaves.len() - 1) as u64
+    }
+
+    /// The number of leaves committed so far.
+    pub fn len(&self) -> u64 {
+        self.leaves.len() as u64
+    }
+
+    /// Whether the MMR has no leaves.
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// Computes the current peaks, ordered from oldest/largest to
+    /// newest/smallest.
+    fn peaks(&self) -> Vec<[u8; 32]> {
+        let mut stack: Vec<(u32, [u8; 32])> = Vec::new();
+        for &leaf in &self.leaves {
+            let mut height = 0u32;
+            let mut hash = leaf;
+            while let Some(&(h, top)) = stack.last() {
+                if h != height {
+                    break;
+                }
+                hash = hash_internal(&top, &hash);
+                stack.pop();
+                height += 1;
+            }
+            stack.push((height, hash));
+        }
+        stack.into_iter().map(|(_, hash)| hash).collect()
+    }
+
+    /// Computes the current MMR root, or `None` if it has no leaves.
+    pub fn root(&self) -> Option<[u8; 32]> {
+        bag_peaks(&self.peaks())
+    }
+
+    /// Generates an inclusion proof for the leaf at `leaf_index`, if present.
+    pub fn gen_proof(&self, leaf_index: u64) -> Option<MmrProof> {
+        let leaf_hash = *self.leaves.get(leaf_index as usize)?;
+
+        // Replay the same append process, recording the sibling chain for
+        // the target leaf whenever it participates in a merge.
+        let mut merkle_path = Vec::new();
+        let mut stack: Vec<(u32, [u8; 32], bool)> = Vec::new(); // (height, hash, contains_target)
+        for (index, &leaf) in self.leaves.iter().enumerate() {
+            let mut height = 0u32;
+            let mut hash = leaf;
+            let mut contains_target = index as u64 == leaf_index;
+            while let Some(&(h, top, top_has_target)) = stack.last() {
+                if h != height {
+                    break;
+                }
+                if top_has_target {
+                    merkle_path.push(hash);
+                } else if contains_target {
+                    merkle_path.push(top);
+                }
+                hash = hash_internal(&top, &hash);
+                contains_target = contains_target || top_has_target;
+                stack.pop();
+                height += 1;
+            }
+            stack.push((height, hash, contains_target));
+        }
+
+        let peaks = stack.into_iter().map(|(_, hash, _)| hash).collect();
+        Some(MmrProof {
+            leaf_index,
+            leaf_hash,
+            merkle_path,
+            peaks,
+            mmr_size: self.len(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mmr_proof_round_trip_for_all_leaves() {
+        let mut mmr = Mmr::new();
+        for i in 0u8..7 {
+            mmr.append(hash_leaf(&[i]));
+        }
+        let root = mmr.root().unwrap();
+        for i in 0..mmr.len() {
+            let proof = mmr.gen_proof(i).unwrap();
+            assert!(proof.verify(&root), "leaf {i} failed to verify");
+        }
+    }
+
+    #[test]
+    fn mmr_proof_rejects_wrong_root() {
+        let mut mmr = Mmr::new();
+        for i in 0u8..3 {
+            mmr.append(hash_leaf(&[i]));
+        }
+        let proof = mmr.gen_proof(1).unwrap();
+        let wrong_root = hash_leaf(b"not the root");
+        assert!(!proof.verify(&wrong_root));
+    }
+}
@@ -8,60 +8,20 @@ use serde::{Serialize, Deserialize};
 use uuid::Uuid;
 use frostgate_zkip::types::ProofMetadata;
 
-/// Supported chain identifiers. Extend as needed for more chains.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, Copy)]
-pub enum ChainId {
-    /// Ethereum blockchain
-    Ethereum,
-    /// Polkadot blockchain
-    Polkadot,
-    /// Solana blockchain
-    Solana,
-    /// Unknown or unsupported chain
-    #[serde(other)]
-    Unknown,
-}
+mod mmr;
+pub use mmr::{hash_internal, hash_leaf, Mmr, MmrProof};
 
-impl ChainId {
-    /// Convert chain ID to u64 for serialization
-    pub fn to_u64(&self) -> u64 {
-        match self {
-            ChainId::Ethereum => 0,
-            ChainId::Polkadot => 1,
-            ChainId::Solana => 2,
-            ChainId::Unknown => u64::MAX,
-        }
-    }
-}
+mod signing;
+use k256::ecdsa::SigningKey;
 
-impl std::fmt::Display for ChainId {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let s = match self {
-            ChainId::Ethereum => "Ethereum",
-            ChainId::Polkadot => "Polkadot",
-            ChainId::Solana => "Solana",
-            ChainId::Unknown => "Unknown",
-        };
-        write!(f, "{}", s)
-    }
-}
+mod chain;
+pub use chain::{ChainFamily, ChainId, ChainInfo, ChainRegistry, FinalityParams};
 
-impl std::convert::TryFrom<u64> for ChainId {
-    type Error = ();
-
-    /// Attempts to convert a u64 into a ChainId.
-    /// 
-    /// # Errors
-    /// Returns `Ok(ChainId::Unknown)` for unrecognized chain IDs.
-    fn try_from(value: u64) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(ChainId::Ethereum),
-            1 => Ok(ChainId::Polkadot),
-            2 => Ok(ChainId::Solana),
-            _ => Ok(ChainId::Unknown),
-        }
-    }
-}
+mod payload;
+pub use payload::{AccessListItem, ChainPayload, EvmCall, SubstrateCall};
+
+mod finality;
+pub use finality::{BlockHeader, FinalityProof, GrandpaJustification, Precommit};
 
 /// A zero-knowledge proof with its metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -97,6 +57,11 @@ pub struct FrostMessage {
     pub fee: Option<u128>,
     /// Extensible metadata for debugging, audit, or protocol extensions.
     pub metadata: Option<HashMap<String, String>>,
+    /// Optional MMR inclusion proof that this message was committed to the
+    /// source chain's append-only message log.
+    pub mmr_proof: Option<MmrProof>,
+    /// Optional destination-chain native call, encoded via [`ChainPayload`].
+    pub chain_payload: Option<Vec<u8>>,
 }
 
 impl FrostMessage {
@@ -126,8 +91,76 @@ impl FrostMessage {
             signature: None,
             fee: None,
             metadata: None,
+            mmr_proof: None,
+            chain_payload: None,
         }
     }
+
+    /// Attaches an MMR inclusion proof to this message.
+    pub fn with_mmr_proof(mut self, mmr_proof: MmrProof) -> Self {
+        self.mmr_proof = Some(mmr_proof);
+        self
+    }
+
+    /// Attaches a destination-chain native call, encoded via `payload`.
+    pub fn with_chain_payload(mut self, payload: &ChainPayload) -> Self {
+        self.chain_payload = Some(payload.encode());
+        self
+    }
+
+    /// Decodes `chain_payload` as a [`ChainPayload`] for `to_chain`'s
+    /// family, verifying it parses as that family's native format. Returns
+    /// `None` if there is no chain payload or it is malformed.
+    pub fn decode_chain_payload(&self) -> Option<ChainPayload> {
+        ChainPayload::decode(self.to_chain.family, self.chain_payload.as_deref()?)
+    }
+
+    /// Canonical bytes of this message's fields, used as the MMR leaf
+    /// preimage. Mirrors the field order of `FrostMessage` itself.
+    fn canonical_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(self.id.as_bytes());
+        buf.extend_from_slice(&self.from_chain.to_u64().to_be_bytes());
+        buf.extend_from_slice(&self.to_chain.to_u64().to_be_bytes());
+        buf.extend_from_slice(&self.payload);
+        buf.extend_from_slice(&self.timestamp.to_be_bytes());
+        buf.extend_from_slice(&self.nonce.to_be_bytes());
+        buf.extend_from_slice(&self.fee.unwrap_or(0).to_be_bytes());
+        buf
+    }
+
+    /// Hashes this message's canonical bytes into the MMR leaf that a
+    /// relayer would commit to the source chain's message log.
+    pub fn mmr_commitment(&self) -> [u8; 32] {
+        hash_leaf(&self.canonical_bytes())
+    }
+
+    /// The hash signed by [`Self::sign_ecdsa`] and checked by
+    /// [`Self::recover_signer`]: `keccak256` of the message's authenticated
+    /// fields (id, from_chain, to_chain, payload, timestamp, nonce, fee),
+    /// deliberately excluding `signature` and `proof`.
+    pub fn signing_hash(&self) -> [u8; 32] {
+        signing::keccak256(&self.canonical_bytes())
+    }
+
+    /// Signs this message with a secp256k1 recoverable signature over
+    /// [`Self::signing_hash`]. `from_chain` is already bound into the signed
+    /// hash via `canonical_bytes()`, so replay protection doesn't depend on
+    /// `from_chain`'s id being folded into the signature itself.
+    pub fn sign_ecdsa(mut self, secret: &SigningKey) -> Self {
+        let hash = self.signing_hash();
+        self.signature = Some(signing::sign_recoverable(secret, &hash));
+        self
+    }
+
+    /// Recovers the 20-byte address that produced `signature`, by
+    /// recomputing [`Self::signing_hash`] and recovering the public key from
+    /// the stored `r || s || v` signature. Returns `None` if there is no
+    /// signature or it is malformed.
+    pub fn recover_signer(&self) -> Option<[u8; 20]> {
+        let hash = self.signing_hash();
+        signing::recover_address(self.signature.as_ref()?, &hash)
+    }
 }
 
 /// A trait for messages that can be sent across chains.
@@ -150,7 +183,7 @@ impl CrossChainMessage for FrostMessage {
     }
 
     fn chain_specific_data(&self) -> Option<&[u8]> {
-        None // FrostMessage uses metadata HashMap for chain-specific data
+        self.chain_payload.as_deref()
     }
 }
 
@@ -161,6 +194,15 @@ pub enum MessageStatus {
     Pending,
     /// Message is being processed
     InFlight,
+    /// Message's source-chain block has been seen but has not yet reached
+    /// finality.
+    AwaitingFinality {
+        /// Confirmations (or finality-gadget votes) observed so far.
+        confirmations: u64,
+        /// Confirmations required before the message can advance to
+        /// `Confirmed`.
+        required: u64,
+    },
     /// Message has been confirmed
     Confirmed,
     /// Message processing failed with error
@@ -183,6 +225,23 @@ pub struct MessageEvent {
     pub tx_hash: Option<TxHash>,
     /// Optional block number where the event was emitted
     pub block_number: Option<u64>,
+    /// Hash of the block where the event was emitted, once known. Checked
+    /// against a [`FinalityProof`] by [`MessageEvent::is_final`] before the
+    /// relay pipeline advances the message to `Confirmed`.
+    pub finalized_block_hash: Option<[u8; 32]>,
+}
+
+impl MessageEvent {
+    /// Whether `proof` attests that this event's block has reached
+    /// finality. `authority_set` is the known validator set to check a
+    /// GRANDPA justification against; it's ignored for confirmation-depth
+    /// proofs. Returns `false` if the emitting block's hash isn't known yet.
+    pub fn is_final(&self, proof: &FinalityProof, authority_set: &[[u8; 32]]) -> bool {
+        match self.finalized_block_hash {
+            Some(hash) => proof.attests(&hash, authority_set),
+            None => false,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -192,8 +251,8 @@ mod tests {
     #[test]
     fn frost_message_basic() {
         let msg = FrostMessage::new(
-            ChainId::Ethereum,
-            ChainId::Solana,
+            ChainId::ETHEREUM,
+            ChainId::SOLANA,
             b"test-payload".to_vec(),
             1,
             1_725_000_000,
@@ -203,4 +262,18 @@ mod tests {
         assert_eq!(msg.from_chain, de.from_chain);
         assert_eq!(msg.payload, de.payload);
     }
+
+    #[test]
+    fn recover_signer_round_trips_for_a_high_network_id_evm_chain() {
+        // Polygon's chain id (137) is past the 111 threshold where folding
+        // a chain id into a one-byte EIP-155 `v` would overflow.
+        let polygon = ChainId::new(ChainFamily::Evm, 137);
+        let secret = SigningKey::random(&mut k256::elliptic_curve::rand_core::OsRng);
+        let expected = signing::address_from_pubkey(secret.verifying_key());
+
+        let msg = FrostMessage::new(polygon, ChainId::ETHEREUM, b"payload".to_vec(), 1, 0)
+            .sign_ecdsa(&secret);
+
+        assert_eq!(msg.recover_signer(), Some(expected));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,87 @@
+//! Ethereum-style recoverable secp256k1 signatures for `FrostMessage`.
+//!
+//! A message is signed over `keccak256` of its canonical, authenticated
+//! fields, producing a 65-byte `r || s || v` signature. The signer's
+//! 20-byte address can then be recovered from the signature and message
+//! hash alone, the same scheme Ethereum uses for transaction and
+//! personal-message signatures.
+
+use k256::ecdsa::signature::hazmat::PrehashSigner;
+use k256::ecdsa::{RecoveryId, Signature, SigningKey, VerifyingKey};
+use sha3::{Digest, Keccak256};
+
+/// Hashes `data` with Keccak-256.
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+/// Derives the 20-byte Ethereum-style address for a public key: the last 20
+/// bytes of `keccak256` of its uncompressed encoding, excluding the
+/// `0x04` prefix byte.
+pub fn address_from_pubkey(verifying_key: &VerifyingKey) -> [u8; 20] {
+    let uncompressed = verifying_key.to_encoded_point(false);
+    let hash = keccak256(&uncompressed.as_bytes()[1..]);
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..]);
+    address
+}
+
+/// Signs `message_hash` with `secret`, returning a 65-byte `r || s || v`
+/// recoverable signature with the legacy `v = recovery_id + 27`.
+///
+/// This deliberately does not fold a chain id into `v`: EIP-155's single
+/// `v` byte overflows for any chain id at or above 111 (`recovery_id +
+/// chain_id * 2 + 35 > 255`), which would silently mis-encode most EVM L2s.
+/// Replay protection instead comes from `from_chain` already being bound
+/// into `signing_hash` via `canonical_bytes()`.
+pub fn sign_recoverable(secret: &SigningKey, message_hash: &[u8; 32]) -> Vec<u8> {
+    let (signature, recovery_id): (Signature, RecoveryId) = secret
+        .sign_prehash_recoverable(message_hash)
+        .expect("signing a 32-byte hash cannot fail");
+
+    let mut bytes = Vec::with_capacity(65);
+    bytes.extend_from_slice(&signature.to_bytes());
+    bytes.push(recovery_id.to_byte() + 27);
+    bytes
+}
+
+/// Recovers the signer's address from a 65-byte `r || s || v` signature
+/// (legacy `v = recovery_id + 27`) and the hash it was signed over.
+pub fn recover_address(signature_bytes: &[u8], message_hash: &[u8; 32]) -> Option<[u8; 20]> {
+    if signature_bytes.len() != 65 {
+        return None;
+    }
+    let (rs, v) = signature_bytes.split_at(64);
+    let recovery_byte = v[0].checked_sub(27)?;
+
+    let signature = Signature::from_slice(rs).ok()?;
+    let recovery_id = RecoveryId::from_byte(recovery_byte)?;
+    let verifying_key =
+        VerifyingKey::recover_from_prehash(message_hash, &signature, recovery_id).ok()?;
+    Some(address_from_pubkey(&verifying_key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recovers_signer() {
+        let secret = SigningKey::random(&mut k256::elliptic_curve::rand_core::OsRng);
+        let expected = address_from_pubkey(secret.verifying_key());
+        let hash = keccak256(b"frostgate-test-message");
+
+        let signature = sign_recoverable(&secret, &hash);
+        let recovered = recover_address(&signature, &hash).unwrap();
+
+        assert_eq!(recovered, expected);
+    }
+
+    #[test]
+    fn recover_rejects_malformed_signature() {
+        let hash = keccak256(b"frostgate-test-message");
+        assert!(recover_address(&[0u8; 10], &hash).is_none());
+    }
+}